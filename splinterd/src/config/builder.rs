@@ -0,0 +1,150 @@
+// Copyright 2018-2020 Cargill Incorporated
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::config::{
+    Config, ConfigError, ConfigSource, PartialConfig, DEFAULT_CERT_DIR, DEFAULT_STATE_DIR,
+};
+
+/// Merges a series of `PartialConfig` layers into a single, fully resolved `Config`.
+///
+/// Layers are added with `with_partial_config` from lowest to highest precedence. When
+/// resolving a value, the last layer added that provides a `Some` wins; a layer whose value is
+/// `None` simply falls through to whichever layer was added before it. `splinterd` assembles its
+/// layers, from lowest to highest precedence, as:
+///
+/// 1. compiled defaults
+/// 2. the TOML config file (`TomlConfig`)
+/// 3. environment variables (`EnvVarConfig`)
+/// 4. command line arguments
+#[derive(Default)]
+pub struct ConfigBuilder {
+    partial_configs: Vec<PartialConfig>,
+}
+
+impl ConfigBuilder {
+    pub fn new() -> Self {
+        ConfigBuilder::default()
+    }
+
+    /// Adds a `PartialConfig`, which takes precedence over any `PartialConfig` added before it.
+    pub fn with_partial_config(mut self, partial_config: PartialConfig) -> Self {
+        self.partial_configs.push(partial_config);
+        self
+    }
+
+    /// Merges all of the added `PartialConfig` layers into a `Config`, filling in any value left
+    /// unset by every layer with the compiled default.
+    pub fn build(self) -> Result<Config, ConfigError> {
+        let mut state_dir = None;
+        let mut state_dir_source = ConfigSource::Default;
+        let mut cert_dir = None;
+        let mut cert_dir_source = ConfigSource::Default;
+
+        for partial_config in self.partial_configs.into_iter() {
+            if let Some(value) = partial_config.state_dir() {
+                state_dir = Some(value);
+                state_dir_source = partial_config.source();
+            }
+            if let Some(value) = partial_config.cert_dir() {
+                cert_dir = Some(value);
+                cert_dir_source = partial_config.source();
+            }
+        }
+
+        Ok(Config {
+            state_dir: state_dir.unwrap_or_else(|| DEFAULT_STATE_DIR.to_string()),
+            state_dir_source,
+            cert_dir: cert_dir.unwrap_or_else(|| DEFAULT_CERT_DIR.to_string()),
+            cert_dir_source,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    /// This test verifies that a ConfigBuilder correctly merges several PartialConfig layers,
+    /// with later layers overriding the values of earlier ones, using the following steps:
+    ///
+    /// 1. Build a Config from no PartialConfig layers at all, and verify it falls back to the
+    ///    compiled defaults for every value.
+    /// 2. Build a Config from a single PartialConfig layer, and verify the resulting Config
+    ///    matches that layer's values and source.
+    /// 3. Build a Config from two PartialConfig layers where the higher-precedence layer sets
+    ///    state_dir but leaves cert_dir unset, and verify that state_dir and its source come from
+    ///    the later layer while cert_dir falls through to the lower-precedence layer.
+    fn test_config_builder_precedence() {
+        // No layers: everything falls back to the compiled defaults.
+        let config = ConfigBuilder::new().build().expect("unable to build config");
+        assert_eq!(config.state_dir(), DEFAULT_STATE_DIR);
+        assert_eq!(config.state_dir_source(), ConfigSource::Default);
+        assert_eq!(config.cert_dir(), DEFAULT_CERT_DIR);
+        assert_eq!(config.cert_dir_source(), ConfigSource::Default);
+
+        // A single layer is used as-is.
+        let file_config = PartialConfig::new(ConfigSource::File {
+            file: "splinterd.toml".to_string(),
+        })
+        .with_state_dir(Some("file/state".to_string()))
+        .with_cert_dir(Some("file/cert".to_string()));
+
+        let config = ConfigBuilder::new()
+            .with_partial_config(file_config)
+            .build()
+            .expect("unable to build config");
+        assert_eq!(config.state_dir(), "file/state");
+        assert_eq!(
+            config.state_dir_source(),
+            ConfigSource::File {
+                file: "splinterd.toml".to_string()
+            }
+        );
+        assert_eq!(config.cert_dir(), "file/cert");
+        assert_eq!(
+            config.cert_dir_source(),
+            ConfigSource::File {
+                file: "splinterd.toml".to_string()
+            }
+        );
+
+        // A higher-precedence layer overrides the values set by a lower-precedence one.
+        let file_config = PartialConfig::new(ConfigSource::File {
+            file: "splinterd.toml".to_string(),
+        })
+        .with_state_dir(Some("file/state".to_string()))
+        .with_cert_dir(Some("file/cert".to_string()));
+        let env_config = PartialConfig::new(ConfigSource::Environment)
+            .with_state_dir(Some("env/state".to_string()))
+            .with_cert_dir(None);
+
+        let config = ConfigBuilder::new()
+            .with_partial_config(file_config)
+            .with_partial_config(env_config)
+            .build()
+            .expect("unable to build config");
+        // The environment layer's state_dir wins.
+        assert_eq!(config.state_dir(), "env/state");
+        assert_eq!(config.state_dir_source(), ConfigSource::Environment);
+        // The environment layer left cert_dir unset, so it falls through to the file layer.
+        assert_eq!(config.cert_dir(), "file/cert");
+        assert_eq!(
+            config.cert_dir_source(),
+            ConfigSource::File {
+                file: "splinterd.toml".to_string()
+            }
+        );
+    }
+}
@@ -0,0 +1,45 @@
+// Copyright 2018-2020 Cargill Incorporated
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::error::Error;
+use std::fmt;
+use std::io;
+
+#[derive(Debug)]
+pub enum ConfigError {
+    ReadError { file: String, err: io::Error },
+    DeserializeError { file: String, err: Box<dyn Error> },
+}
+
+impl Error for ConfigError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ConfigError::ReadError { err, .. } => Some(err),
+            ConfigError::DeserializeError { err, .. } => Some(err.as_ref()),
+        }
+    }
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ConfigError::ReadError { file, err } => {
+                write!(f, "unable to read config file '{}': {}", file, err)
+            }
+            ConfigError::DeserializeError { file, err } => {
+                write!(f, "unable to parse config file '{}': {}", file, err)
+            }
+        }
+    }
+}
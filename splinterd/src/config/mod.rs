@@ -0,0 +1,123 @@
+// Copyright 2018-2020 Cargill Incorporated
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+mod builder;
+mod env;
+mod error;
+mod toml;
+
+use std::fmt;
+
+pub use builder::ConfigBuilder;
+pub use env::EnvVarConfig;
+pub use error::ConfigError;
+pub use toml::TomlConfig;
+
+const DEFAULT_STATE_DIR: &str = "/var/lib/splinter";
+const DEFAULT_CERT_DIR: &str = "/etc/splinter/certs";
+
+/// Identifies where a `PartialConfig`'s values came from, so the fully resolved `Config` can
+/// report which layer supplied a given value.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConfigSource {
+    Default,
+    Environment,
+    File { file: String },
+}
+
+impl fmt::Display for ConfigSource {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ConfigSource::Default => write!(f, "default values"),
+            ConfigSource::Environment => write!(f, "environment variables"),
+            ConfigSource::File { file } => write!(f, "config file '{}'", file),
+        }
+    }
+}
+
+/// Holds the configuration values gathered from a single `ConfigSource`. Any value may be unset
+/// (`None`) if that source does not provide it, in which case it falls through to whichever
+/// lower-precedence layer does.
+#[derive(Debug)]
+pub struct PartialConfig {
+    source: ConfigSource,
+    state_dir: Option<String>,
+    cert_dir: Option<String>,
+}
+
+impl PartialConfig {
+    pub fn new(source: ConfigSource) -> Self {
+        PartialConfig {
+            source,
+            state_dir: None,
+            cert_dir: None,
+        }
+    }
+
+    pub fn source(&self) -> ConfigSource {
+        self.source.clone()
+    }
+
+    pub fn state_dir(&self) -> Option<String> {
+        self.state_dir.clone()
+    }
+
+    pub fn cert_dir(&self) -> Option<String> {
+        self.cert_dir.clone()
+    }
+
+    pub fn with_state_dir(mut self, state_dir: Option<String>) -> Self {
+        self.state_dir = state_dir;
+        self
+    }
+
+    pub fn with_cert_dir(mut self, cert_dir: Option<String>) -> Self {
+        self.cert_dir = cert_dir;
+        self
+    }
+}
+
+/// Builds a `PartialConfig` from a particular configuration source, such as a TOML file, the
+/// environment, or command line arguments.
+pub trait PartialConfigBuilder {
+    fn build(self) -> PartialConfig;
+}
+
+/// The fully resolved configuration used by splinterd, produced by merging a series of
+/// `PartialConfig` layers together through a `ConfigBuilder`.
+#[derive(Debug)]
+pub struct Config {
+    state_dir: String,
+    state_dir_source: ConfigSource,
+    cert_dir: String,
+    cert_dir_source: ConfigSource,
+}
+
+impl Config {
+    pub fn state_dir(&self) -> &str {
+        &self.state_dir
+    }
+
+    pub fn state_dir_source(&self) -> ConfigSource {
+        self.state_dir_source.clone()
+    }
+
+    pub fn cert_dir(&self) -> &str {
+        &self.cert_dir
+    }
+
+    pub fn cert_dir_source(&self) -> ConfigSource {
+        self.cert_dir_source.clone()
+    }
+}
@@ -0,0 +1,117 @@
+// Copyright 2018-2020 Cargill Incorporated
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::fs;
+
+use serde::Deserialize;
+
+use crate::config::{ConfigError, ConfigSource, PartialConfig, PartialConfigBuilder};
+
+/// The subset of configuration values that may appear in a `splinterd.toml` file.
+#[derive(Deserialize, Default, Debug)]
+struct TomlConfigValues {
+    state_dir: Option<String>,
+    cert_dir: Option<String>,
+}
+
+/// Holds configuration values read from a TOML file, such as `splinterd.toml`.
+pub struct TomlConfig {
+    file: String,
+    values: TomlConfigValues,
+}
+
+impl TomlConfig {
+    /// Reads and parses the TOML file at `file`, returning a `TomlConfig` that can be turned
+    /// into a `PartialConfig` via `PartialConfigBuilder::build`.
+    pub fn load_from_file(file: &str) -> Result<TomlConfig, ConfigError> {
+        let contents = fs::read_to_string(file).map_err(|err| ConfigError::ReadError {
+            file: file.to_string(),
+            err,
+        })?;
+
+        let values: TomlConfigValues =
+            ::toml::from_str(&contents).map_err(|err| ConfigError::DeserializeError {
+                file: file.to_string(),
+                err: Box::new(err),
+            })?;
+
+        Ok(TomlConfig {
+            file: file.to_string(),
+            values,
+        })
+    }
+}
+
+impl PartialConfigBuilder for TomlConfig {
+    fn build(self) -> PartialConfig {
+        PartialConfig::new(ConfigSource::File { file: self.file })
+            .with_state_dir(self.values.state_dir)
+            .with_cert_dir(self.values.cert_dir)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::fs::{remove_file, File};
+    use std::io::Write;
+
+    #[test]
+    /// This test verifies that a PartialConfig object, constructed from the TomlConfig module,
+    /// contains the correct values using the following steps:
+    ///
+    /// 1. Write a TOML file containing a `state_dir` and `cert_dir` value to a temporary path.
+    /// 2. Load a TomlConfig from that file.
+    /// 3. Build a PartialConfig from the TomlConfig.
+    ///
+    /// This test then verifies that the resulting PartialConfig reports the correct source
+    /// (`ConfigSource::File`, naming the TOML file that was read) and the correct values for
+    /// each configuration field.
+    fn test_toml_file_config() {
+        let path = format!(
+            "{}/splinterd-toml-test-{}.toml",
+            std::env::temp_dir().display(),
+            std::process::id()
+        );
+
+        let mut file = File::create(&path).expect("unable to create temp TOML file");
+        writeln!(file, "state_dir = \"state/test/config\"").expect("unable to write to temp file");
+        writeln!(file, "cert_dir = \"cert/test/config\"").expect("unable to write to temp file");
+        drop(file);
+
+        let toml_config = TomlConfig::load_from_file(&path).expect("unable to load TomlConfig");
+        let config = toml_config.build();
+
+        assert_eq!(config.source(), ConfigSource::File { file: path.clone() });
+        assert_eq!(
+            config.state_dir(),
+            Some(String::from("state/test/config"))
+        );
+        assert_eq!(config.cert_dir(), Some(String::from("cert/test/config")));
+
+        remove_file(&path).expect("unable to remove temp TOML file");
+    }
+
+    #[test]
+    /// This test verifies that attempting to load a TomlConfig from a file which does not exist
+    /// results in a `ConfigError::ReadError`.
+    fn test_toml_file_config_missing_file() {
+        let result = TomlConfig::load_from_file("/nonexistent/splinterd.toml");
+        match result {
+            Err(ConfigError::ReadError { .. }) => (),
+            _ => panic!("expected a ConfigError::ReadError"),
+        }
+    }
+}